@@ -0,0 +1,77 @@
+//! Localhost-only HTTP control/metrics server.
+//!
+//! Mirrors the `ping` command over REST so local tools and CI monitors can
+//! drive NetMonitor without going through the WebView. Never binds anything
+//! but loopback, and reuses the same whitelist check as the Tauri command so
+//! the HTTP surface can't be used to probe arbitrary hosts.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use crate::{ping_target, targets, AppState, PingResult};
+
+/// Default port for the control server; overridden by `NETMONITOR_CONTROL_PORT`.
+const DEFAULT_PORT: u16 = 4727;
+
+#[derive(Deserialize)]
+struct PingRequest {
+    target: String,
+}
+
+async fn get_targets(State(state): State<Arc<AppState>>) -> Json<Vec<String>> {
+    Json(targets::list(&state))
+}
+
+async fn get_health() -> &'static str {
+    "ok"
+}
+
+async fn post_ping(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<PingRequest>,
+) -> Result<Json<PingResult>, (StatusCode, String)> {
+    ping_target(req.target, &state)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::FORBIDDEN, e))
+}
+
+fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/targets", get(get_targets))
+        .route("/ping", post(post_ping))
+        .route("/health", get(get_health))
+        .with_state(state)
+}
+
+/// Starts the control server on loopback and serves it until the process exits.
+///
+/// Intended to be spawned via `tauri::async_runtime::spawn` from `setup`. Any
+/// bind failure (e.g. the configured port is already in use) is logged rather
+/// than panicking the app.
+pub async fn start(state: Arc<AppState>) {
+    let port = std::env::var("NETMONITOR_CONTROL_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(DEFAULT_PORT);
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+
+    let listener = match tokio::net::TcpListener::bind(addr).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("control server: failed to bind {addr}: {e}");
+            return;
+        }
+    };
+
+    log::info!("control server listening on {addr}");
+    if let Err(e) = axum::serve(listener, router(state)).await {
+        log::error!("control server exited: {e}");
+    }
+}
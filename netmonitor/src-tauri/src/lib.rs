@@ -1,55 +1,63 @@
-use tauri::{Manager, State};
-use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 
-struct AppState {
-    client: reqwest::Client,
+mod control_server;
+mod monitoring;
+mod ping;
+mod protocol;
+mod targets;
+
+pub(crate) use ping::{ping_target, PingResult};
+use monitoring::{HistoryAggregate, MonitorState};
+
+pub(crate) struct AppState {
+    db: sqlx::SqlitePool,
+    monitor: tokio::sync::Mutex<MonitorState>,
+    targets: Mutex<HashSet<String>>,
+}
+
+#[tauri::command]
+async fn ping(url: String, state: State<'_, Arc<AppState>>) -> Result<PingResult, String> {
+    ping_target(url, &state).await
 }
 
-#[derive(serde::Serialize)]
-pub struct PingResult {
-    pub success: bool,
-    pub latency_ms: u64,
+#[tauri::command]
+async fn start_monitoring(
+    interval_secs: u64,
+    app: AppHandle,
+    state: State<'_, Arc<AppState>>,
+) -> Result<(), String> {
+    monitoring::start(app, state.inner().clone(), interval_secs).await
 }
 
-// Whitelist of allowed ping targets (IPs and domains)
-const ALLOWED_TARGETS: [&str; 6] = [
-    "8.8.8.8",
-    "1.1.1.1",
-    "9.9.9.9",
-    "208.67.222.222",
-    "www.google.com",
-    "www.cloudflare.com"
-];
+#[tauri::command]
+async fn stop_monitoring(state: State<'_, Arc<AppState>>) -> Result<(), String> {
+    monitoring::stop(state.inner().clone()).await
+}
 
 #[tauri::command]
-async fn ping(url: String, state: State<'_, AppState>) -> Result<PingResult, String> {
-    // Security Check: Validate that the requested URL is in the whitelist
-    if !ALLOWED_TARGETS.contains(&url.as_str()) {
-        return Err(format!("Target '{}' not in whitelist", url));
-    }
-
-    // Construct full URL with https scheme
-    let full_url = format!("https://{}", url);
-
-    let start = Instant::now();
-    // Use the shared client from AppState
-    let response = state.client.head(&full_url).send().await;
-    let latency_ms = start.elapsed().as_millis() as u64;
-
-    match response {
-        Ok(resp) => {
-            Ok(PingResult {
-                success: resp.status().is_success(),
-                latency_ms,
-            })
-        }
-        Err(_e) => {
-            Ok(PingResult {
-                success: false,
-                latency_ms: 0,
-            })
-        }
-    }
+async fn get_history(
+    target: String,
+    since_ts: i64,
+    state: State<'_, Arc<AppState>>,
+) -> Result<HistoryAggregate, String> {
+    monitoring::history(state.inner().clone(), target, since_ts).await
+}
+
+#[tauri::command]
+fn add_target(host: String, app: AppHandle, state: State<'_, Arc<AppState>>) -> Result<(), String> {
+    targets::add(&app, &state, host)
+}
+
+#[tauri::command]
+fn remove_target(host: String, app: AppHandle, state: State<'_, Arc<AppState>>) -> Result<(), String> {
+    targets::remove(&app, &state, host)
+}
+
+#[tauri::command]
+fn list_targets(state: State<'_, Arc<AppState>>) -> Vec<String> {
+    targets::list(&state)
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -61,21 +69,32 @@ pub fn run() {
     .plugin(tauri_plugin_shell::init())
     .plugin(tauri_plugin_store::Builder::default().build())
     .plugin(tauri_plugin_sql::Builder::new().build())
-    .invoke_handler(tauri::generate_handler![ping])
+    .register_asynchronous_uri_scheme_protocol("netmon", protocol::handler)
+    .invoke_handler(tauri::generate_handler![
+      ping,
+      start_monitoring,
+      stop_monitoring,
+      get_history,
+      add_target,
+      remove_target,
+      list_targets
+    ])
     .setup(|app| {
-      // Initialize the reqwest client once and manage it in AppState
-      // Force HTTP/1 only and disable all connection pooling/reuse
-      // to ensure each ping measures full connection latency (DNS + TCP + TLS + HTTP)
-      let client = reqwest::Client::builder()
-        .timeout(std::time::Duration::from_secs(5))
-        .http1_only()
-        .pool_max_idle_per_host(0)
-        .pool_idle_timeout(std::time::Duration::ZERO)
-        .tcp_keepalive(None)
-        .build()
-        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
-
-      app.manage(AppState { client });
+      let db = tauri::async_runtime::block_on(monitoring::init_db(app.handle()))
+        .map_err(|e| Box::<dyn std::error::Error>::from(e))?;
+      let targets = targets::load(app.handle())
+        .map_err(|e| Box::<dyn std::error::Error>::from(e))?;
+
+      let state = Arc::new(AppState {
+        db,
+        monitor: tokio::sync::Mutex::new(MonitorState::default()),
+        targets: Mutex::new(targets),
+      });
+      app.manage(state.clone());
+
+      // Expose the ping subsystem over a loopback-only HTTP API so local
+      // tools/CI can drive NetMonitor without the WebView.
+      tauri::async_runtime::spawn(control_server::start(state));
 
       // Open DevTools for debugging
       if let Some(window) = app.get_webview_window("main") {
@@ -0,0 +1,232 @@
+//! Background monitoring: periodically pings every whitelisted target,
+//! persists each sample to SQLite, and emits a `ping-sample` event so the
+//! frontend can render live charts.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Upper bound for a single target's ping within a sampling cycle. Each of
+/// `ping_target`'s four phases already carries its own timeout, but this
+/// outer deadline is the belt-and-braces guarantee that one unresponsive
+/// target can never stall the rest of the cycle.
+const PING_TIMEOUT: Duration = Duration::from_secs(20);
+
+use serde::Serialize;
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Row, SqlitePool};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::ping::ping_target;
+use crate::{targets, AppState};
+
+pub(crate) struct MonitorState {
+    handle: Option<tauri::async_runtime::JoinHandle<()>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Default for MonitorState {
+    fn default() -> Self {
+        Self {
+            handle: None,
+            stop: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct PingSample {
+    pub target: String,
+    pub ts: i64,
+    pub success: bool,
+    pub latency_ms: i64,
+}
+
+#[derive(Serialize)]
+pub struct HistoryAggregate {
+    pub min_ms: i64,
+    pub avg_ms: f64,
+    pub max_ms: i64,
+    pub loss_rate: f64,
+    pub sample_count: i64,
+}
+
+/// Opens (creating if needed) the SQLite database used for ping history, in
+/// the app's data directory, and ensures the `ping_samples` table exists.
+pub(crate) async fn init_db(app: &AppHandle) -> Result<SqlitePool, String> {
+    let data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("could not resolve app data dir: {e}"))?;
+    std::fs::create_dir_all(&data_dir).map_err(|e| format!("could not create app data dir: {e}"))?;
+    let db_path = data_dir.join("netmonitor.db");
+
+    let pool = SqlitePoolOptions::new()
+        .connect(&format!("sqlite://{}?mode=rwc", db_path.display()))
+        .await
+        .map_err(|e| format!("could not open database: {e}"))?;
+
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS ping_samples (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            target TEXT NOT NULL,
+            ts INTEGER NOT NULL,
+            success INTEGER NOT NULL,
+            latency_ms INTEGER NOT NULL
+        )",
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("could not create ping_samples table: {e}"))?;
+
+    Ok(pool)
+}
+
+fn now_ts() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+async fn sample_once(db: &SqlitePool, app: &AppHandle, state: &AppState) {
+    for target in targets::list(state) {
+        let result = tokio::time::timeout(PING_TIMEOUT, ping_target(target.clone(), state)).await;
+        let (success, latency_ms) = match result {
+            Ok(Ok(r)) => (r.success, r.latency_ms as i64),
+            Ok(Err(_)) => (false, 0),
+            Err(_) => {
+                log::warn!("monitoring: ping to {target} exceeded {PING_TIMEOUT:?}, skipping");
+                (false, 0)
+            }
+        };
+        let sample = PingSample {
+            target: target.to_string(),
+            ts: now_ts(),
+            success,
+            latency_ms,
+        };
+
+        let insert = sqlx::query(
+            "INSERT INTO ping_samples (target, ts, success, latency_ms) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&sample.target)
+        .bind(sample.ts)
+        .bind(sample.success)
+        .bind(sample.latency_ms)
+        .execute(db)
+        .await;
+
+        if let Err(e) = insert {
+            log::error!("monitoring: failed to persist sample for {target}: {e}");
+            continue;
+        }
+
+        let _ = app.emit("ping-sample", &sample);
+    }
+}
+
+/// Starts the monitoring scheduler if it isn't already running.
+pub(crate) async fn start(
+    app: AppHandle,
+    state: Arc<AppState>,
+    interval_secs: u64,
+) -> Result<(), String> {
+    let mut monitor = state.monitor.lock().await;
+    if monitor.handle.is_some() {
+        return Err("monitoring is already running".into());
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_clone = stop.clone();
+    let db = state.db.clone();
+    let state_clone = state.clone();
+
+    let handle = tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs.max(1)));
+        loop {
+            ticker.tick().await;
+            if stop_clone.load(Ordering::Relaxed) {
+                break;
+            }
+            sample_once(&db, &app, &state_clone).await;
+        }
+    });
+
+    monitor.handle = Some(handle);
+    monitor.stop = stop;
+    Ok(())
+}
+
+/// Stops the monitoring scheduler if it is running.
+pub(crate) async fn stop(state: Arc<AppState>) -> Result<(), String> {
+    let mut monitor = state.monitor.lock().await;
+    monitor.stop.store(true, Ordering::Relaxed);
+    if let Some(handle) = monitor.handle.take() {
+        handle.abort();
+    }
+    Ok(())
+}
+
+/// Returns min/avg/max latency and loss rate for `target` since `since_ts`
+/// (unix seconds).
+pub(crate) async fn history(
+    state: Arc<AppState>,
+    target: String,
+    since_ts: i64,
+) -> Result<HistoryAggregate, String> {
+    let row = sqlx::query(
+        "SELECT
+            COUNT(*) as sample_count,
+            COALESCE(MIN(CASE WHEN success THEN latency_ms END), 0) as min_ms,
+            COALESCE(AVG(CASE WHEN success THEN latency_ms END), 0.0) as avg_ms,
+            COALESCE(MAX(CASE WHEN success THEN latency_ms END), 0) as max_ms,
+            COALESCE(AVG(CASE WHEN success THEN 0.0 ELSE 1.0 END), 0.0) as loss_rate
+        FROM ping_samples
+        WHERE target = ? AND ts >= ?",
+    )
+    .bind(&target)
+    .bind(since_ts)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| format!("could not query history: {e}"))?;
+
+    Ok(HistoryAggregate {
+        sample_count: row.try_get("sample_count").unwrap_or(0),
+        min_ms: row.try_get("min_ms").unwrap_or(0),
+        avg_ms: row.try_get("avg_ms").unwrap_or(0.0),
+        max_ms: row.try_get("max_ms").unwrap_or(0),
+        loss_rate: row.try_get("loss_rate").unwrap_or(0.0),
+    })
+}
+
+/// Returns the raw `ping_samples` rows for `target` since `since_ts` (unix
+/// seconds), oldest first, for callers that need the time series itself
+/// rather than the aggregate in [`history`].
+pub(crate) async fn samples_since(
+    state: Arc<AppState>,
+    target: String,
+    since_ts: i64,
+) -> Result<Vec<PingSample>, String> {
+    let rows = sqlx::query(
+        "SELECT target, ts, success, latency_ms
+        FROM ping_samples
+        WHERE target = ? AND ts >= ?
+        ORDER BY ts ASC",
+    )
+    .bind(&target)
+    .bind(since_ts)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| format!("could not query samples: {e}"))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| PingSample {
+            target: row.try_get("target").unwrap_or_default(),
+            ts: row.try_get("ts").unwrap_or_default(),
+            success: row.try_get("success").unwrap_or_default(),
+            latency_ms: row.try_get("latency_ms").unwrap_or_default(),
+        })
+        .collect())
+}
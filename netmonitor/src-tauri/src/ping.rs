@@ -0,0 +1,117 @@
+//! Whitelisted ping probing, broken down into DNS / TCP-connect /
+//! TLS-handshake / TTFB phases so a slow ping is actionable instead of one
+//! opaque number.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rustls_pki_types::ServerName;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+use tokio_rustls::TlsConnector;
+
+use crate::AppState;
+
+/// Per-phase deadline. Mirrors the 5s timeout the baseline `reqwest` client
+/// applied to the whole request, now applied to each phase so a stuck DNS
+/// resolver or a blackholed host can't hang a probe indefinitely.
+const PHASE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(serde::Serialize, Default)]
+pub struct PingResult {
+    pub success: bool,
+    /// Total round-trip time, i.e. the sum of the phases below. Kept for
+    /// backwards compatibility with consumers of the original single-number
+    /// `ping` result.
+    pub latency_ms: u64,
+    pub dns_ms: u64,
+    pub connect_ms: u64,
+    pub tls_ms: u64,
+    pub ttfb_ms: u64,
+}
+
+fn tls_connector() -> TlsConnector {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+}
+
+/// Pings `target` after checking it against the live target list, timing DNS
+/// resolution, TCP connect, TLS handshake, and time-to-first-byte
+/// separately. Shared between the `ping` Tauri command and the localhost
+/// control server so both surfaces enforce the exact same rule.
+pub(crate) async fn ping_target(target: String, state: &AppState) -> Result<PingResult, String> {
+    // Security Check: Validate that the requested target is in the live list
+    let is_allowed = state.targets.lock().unwrap().contains(&target);
+    if !is_allowed {
+        return Err(format!("Target '{}' not in target list", target));
+    }
+
+    let mut result = PingResult::default();
+    macro_rules! finish {
+        () => {{
+            result.latency_ms = result.dns_ms + result.connect_ms + result.tls_ms + result.ttfb_ms;
+            return Ok(result);
+        }};
+    }
+
+    let start = Instant::now();
+    let addr = match timeout(PHASE_TIMEOUT, tokio::net::lookup_host((target.as_str(), 443))).await
+    {
+        Ok(Ok(mut addrs)) => match addrs.next() {
+            Some(addr) => addr,
+            None => finish!(),
+        },
+        Ok(Err(_)) | Err(_) => finish!(),
+    };
+    result.dns_ms = start.elapsed().as_millis() as u64;
+
+    let start = Instant::now();
+    let stream = match timeout(PHASE_TIMEOUT, TcpStream::connect(addr)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(_)) | Err(_) => finish!(),
+    };
+    result.connect_ms = start.elapsed().as_millis() as u64;
+
+    let start = Instant::now();
+    let server_name = match ServerName::try_from(target.clone()) {
+        Ok(name) => name,
+        Err(_) => finish!(),
+    };
+    let tls_stream = match timeout(PHASE_TIMEOUT, tls_connector().connect(server_name, stream))
+        .await
+    {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(_)) | Err(_) => finish!(),
+    };
+    result.tls_ms = start.elapsed().as_millis() as u64;
+
+    let start = Instant::now();
+    let request = format!("HEAD / HTTP/1.1\r\nHost: {target}\r\nConnection: close\r\n\r\n");
+    let mut reader = BufReader::new(tls_stream);
+    let status_line = timeout(PHASE_TIMEOUT, async {
+        reader.write_all(request.as_bytes()).await?;
+        let mut line = String::new();
+        reader.read_line(&mut line).await?;
+        Ok::<_, std::io::Error>(line)
+    })
+    .await;
+    let Ok(Ok(status_line)) = status_line else {
+        finish!();
+    };
+    result.ttfb_ms = start.elapsed().as_millis() as u64;
+
+    // e.g. "HTTP/1.1 200 OK\r\n" -> the HTTP status code is the second
+    // whitespace-separated field.
+    result.success = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .is_some_and(|code| (200..300).contains(&code));
+
+    finish!();
+}
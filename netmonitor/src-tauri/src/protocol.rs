@@ -0,0 +1,166 @@
+//! Serves the monitoring dashboard and a live JSON samples feed over a
+//! custom `netmon://` URI scheme, so the data stays on an app-controlled
+//! origin instead of being piped entirely through `invoke`.
+
+use std::sync::Arc;
+
+use tauri::http::{Request, Response, StatusCode};
+use tauri::{AppHandle, Manager, UriSchemeContext, UriSchemeResponder};
+
+use crate::{monitoring, AppState};
+
+const DASHBOARD_INDEX: &str = include_str!("../dashboard/index.html");
+
+/// Decodes `application/x-www-form-urlencoded` escaping (`%XX` and `+` for
+/// space) so percent-encoded query values round-trip correctly.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => {
+                out.push(b' ');
+                i += 1;
+            }
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 3;
+                    }
+                    None => {
+                        out.push(bytes[i]);
+                        i += 1;
+                    }
+                }
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn query_param(query: &str, key: &str) -> Option<String> {
+    query.split('&').find_map(|pair| {
+        let (k, v) = pair.split_once('=')?;
+        (k == key).then(|| percent_decode(v))
+    })
+}
+
+fn html_response(body: &str) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", "text/html; charset=utf-8")
+        .body(body.as_bytes().to_vec())
+        .unwrap()
+}
+
+fn json_response(status: StatusCode, body: &impl serde::Serialize) -> Response<Vec<u8>> {
+    let bytes = serde_json::to_vec(body).unwrap_or_default();
+    Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(bytes)
+        .unwrap()
+}
+
+fn not_found() -> Response<Vec<u8>> {
+    Response::builder()
+        .status(StatusCode::NOT_FOUND)
+        .body(Vec::new())
+        .unwrap()
+}
+
+async fn handle(app: AppHandle, request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let uri = request.uri();
+    let path = uri.path();
+    let query = uri.query().unwrap_or("");
+
+    // `netmon://samples?target=...` (no path segment) parses `samples` as the
+    // URI *authority*, not the path, so accept it as a host alias for
+    // `/samples` alongside the `netmon://<host>/samples` form the bundled
+    // dashboard uses.
+    let wants_samples = path == "/samples" || uri.host() == Some("samples");
+
+    if wants_samples {
+        let Some(target) = query_param(query, "target") else {
+            return json_response(StatusCode::BAD_REQUEST, &"missing 'target' query param");
+        };
+        let since_ts = query_param(query, "since")
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+
+        let state = app.state::<Arc<AppState>>().inner().clone();
+        return match monitoring::samples_since(state, target, since_ts).await {
+            Ok(samples) => json_response(StatusCode::OK, &samples),
+            Err(e) => json_response(StatusCode::INTERNAL_SERVER_ERROR, &e),
+        };
+    }
+
+    match path {
+        "/" | "/index.html" => html_response(DASHBOARD_INDEX),
+        _ => not_found(),
+    }
+}
+
+/// Handler passed to `register_asynchronous_uri_scheme_protocol`. Spawns the
+/// actual request handling so it can `await` the database query before
+/// responding.
+pub(crate) fn handler(
+    ctx: UriSchemeContext<'_, tauri::Wry>,
+    request: Request<Vec<u8>>,
+    responder: UriSchemeResponder,
+) {
+    let app = ctx.app_handle().clone();
+    tauri::async_runtime::spawn(async move {
+        responder.respond(handle(app, request).await);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{percent_decode, query_param};
+
+    #[test]
+    fn percent_decode_passes_through_plain_text() {
+        assert_eq!(percent_decode("8.8.8.8"), "8.8.8.8");
+    }
+
+    #[test]
+    fn percent_decode_decodes_hex_escapes() {
+        assert_eq!(percent_decode("a%2Bb"), "a+b");
+        assert_eq!(percent_decode("100%25"), "100%");
+    }
+
+    #[test]
+    fn percent_decode_turns_plus_into_space() {
+        assert_eq!(percent_decode("a+b"), "a b");
+    }
+
+    #[test]
+    fn percent_decode_leaves_truncated_escape_untouched() {
+        assert_eq!(percent_decode("abc%2"), "abc%2");
+    }
+
+    #[test]
+    fn query_param_finds_and_decodes_value() {
+        assert_eq!(
+            query_param("target=8.8.8.8&since=0", "target"),
+            Some("8.8.8.8".to_string())
+        );
+        assert_eq!(
+            query_param("target=a%2Bb", "target"),
+            Some("a+b".to_string())
+        );
+    }
+
+    #[test]
+    fn query_param_missing_key_returns_none() {
+        assert_eq!(query_param("since=0", "target"), None);
+    }
+}
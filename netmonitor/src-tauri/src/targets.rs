@@ -0,0 +1,162 @@
+//! Runtime-editable ping target list, persisted through
+//! `tauri-plugin-store` so user edits survive restarts.
+
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::AppState;
+
+const STORE_FILE: &str = "targets.json";
+const TARGETS_KEY: &str = "targets";
+
+// Default target list used to seed the store on first run.
+const DEFAULT_TARGETS: [&str; 6] = [
+    "8.8.8.8",
+    "1.1.1.1",
+    "9.9.9.9",
+    "208.67.222.222",
+    "www.google.com",
+    "www.cloudflare.com",
+];
+
+/// Rejects anything that isn't a bare IPv4/IPv6 literal or a syntactically
+/// valid hostname — no scheme, no path, no port, no credentials — so a
+/// target, which `ping_target` uses as the DNS lookup name (port 443), the
+/// TLS server name and the HTTP `Host` header, can never be abused to reach
+/// an unintended endpoint.
+fn validate_host(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        return Err("target must not be empty".into());
+    }
+    if IpAddr::from_str(host).is_ok() {
+        return Ok(());
+    }
+
+    if host.contains("://") || host.contains('/') || host.contains('@') || host.contains(':') {
+        return Err(format!("'{host}' must be a bare host, not a URL"));
+    }
+    if host.len() > 253 {
+        return Err(format!("'{host}' is too long to be a hostname"));
+    }
+
+    for label in host.split('.') {
+        let valid_label = !label.is_empty()
+            && label.len() <= 63
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !valid_label {
+            return Err(format!("'{host}' is not a valid hostname"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the persisted target list, seeding the store with the default
+/// targets on first run.
+pub(crate) fn load(app: &AppHandle) -> Result<HashSet<String>, String> {
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("could not open target store: {e}"))?;
+
+    match store.get(TARGETS_KEY) {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|e| format!("could not parse stored targets: {e}")),
+        None => {
+            let defaults: HashSet<String> =
+                DEFAULT_TARGETS.iter().map(|t| t.to_string()).collect();
+            store.set(TARGETS_KEY, serde_json::json!(defaults));
+            store
+                .save()
+                .map_err(|e| format!("could not persist default targets: {e}"))?;
+            Ok(defaults)
+        }
+    }
+}
+
+fn persist(app: &AppHandle, targets: &HashSet<String>) -> Result<(), String> {
+    let store = app
+        .store(STORE_FILE)
+        .map_err(|e| format!("could not open target store: {e}"))?;
+    store.set(TARGETS_KEY, serde_json::json!(targets));
+    store
+        .save()
+        .map_err(|e| format!("could not persist targets: {e}"))
+}
+
+pub(crate) fn add(app: &AppHandle, state: &AppState, host: String) -> Result<(), String> {
+    validate_host(&host)?;
+    let mut targets = state.targets.lock().unwrap();
+    targets.insert(host);
+    persist(app, &targets)
+}
+
+pub(crate) fn remove(app: &AppHandle, state: &AppState, host: String) -> Result<(), String> {
+    let mut targets = state.targets.lock().unwrap();
+    targets.remove(&host);
+    persist(app, &targets)
+}
+
+pub(crate) fn list(state: &AppState) -> Vec<String> {
+    let mut targets: Vec<String> = state.targets.lock().unwrap().iter().cloned().collect();
+    targets.sort();
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_host;
+
+    #[test]
+    fn accepts_ipv4_literal() {
+        assert!(validate_host("8.8.8.8").is_ok());
+    }
+
+    #[test]
+    fn accepts_ipv6_literal() {
+        assert!(validate_host("2001:4860:4860::8888").is_ok());
+    }
+
+    #[test]
+    fn accepts_plain_hostname() {
+        assert!(validate_host("www.cloudflare.com").is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_host() {
+        assert!(validate_host("").is_err());
+    }
+
+    #[test]
+    fn rejects_scheme() {
+        assert!(validate_host("https://example.com").is_err());
+    }
+
+    #[test]
+    fn rejects_path() {
+        assert!(validate_host("example.com/path").is_err());
+    }
+
+    #[test]
+    fn rejects_port() {
+        assert!(validate_host("example.com:8080").is_err());
+    }
+
+    #[test]
+    fn rejects_credentials() {
+        assert!(validate_host("user@example.com").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_label() {
+        assert!(validate_host("-example.com").is_err());
+        assert!(validate_host("example..com").is_err());
+    }
+}